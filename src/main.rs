@@ -5,7 +5,7 @@ pub mod helpers;
 
 extern crate clap;
 
-use clap::{App, Arg};
+use clap::{App, Arg, ArgMatches, SubCommand};
 
 mod construction;
 mod models;
@@ -18,64 +18,256 @@ mod solver;
 pub use self::solver::Solver;
 use crate::models::{Problem, Solution};
 use crate::streams::input::text::{LilimProblem, SolomonProblem};
-use crate::streams::output::text::write_solomon_solution;
+use crate::streams::output::text::{write_dot_solution, write_solomon_solution};
 use std::collections::HashMap;
-use std::io::{stdout, BufWriter, Error};
+use std::fs::File;
+use std::io::{stdout, BufWriter, Error, ErrorKind, Write};
 use std::ops::Deref;
+use std::path::Path;
 use std::process;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
 
 struct InputReader(Box<dyn Fn(String) -> Result<Problem, String>>);
 
-struct OutputWriter(Box<dyn Fn(Solution) -> Result<(), Error>>);
+struct OutputWriter(Box<dyn Fn(Solution, Box<dyn Write>) -> Result<(), Error>>);
 
-fn main() {
-    let readers: HashMap<&str, InputReader> = vec![
+fn get_readers() -> HashMap<&'static str, InputReader> {
+    vec![
         ("solomon", InputReader(Box::new(|path: String| path.parse_solomon()))),
         ("lilim", InputReader(Box::new(|path: String| path.parse_lilim()))),
     ]
     .into_iter()
-    .collect();
-
-    let writers: HashMap<&str, OutputWriter> = vec![(
-        "solomon",
-        OutputWriter(Box::new(|solution: Solution| {
-            write_solomon_solution(BufWriter::new(Box::new(stdout())), &solution)
-        })),
-    )]
+    .collect()
+}
+
+fn get_writers(dark_mode: bool) -> HashMap<&'static str, OutputWriter> {
+    vec![
+        (
+            "solomon",
+            OutputWriter(Box::new(|solution: Solution, writer: Box<dyn Write>| write_solomon_solution(writer, &solution))),
+        ),
+        (
+            "dot",
+            OutputWriter(Box::new(move |solution: Solution, writer: Box<dyn Write>| {
+                write_dot_solution(writer, &solution, dark_mode)
+            })),
+        ),
+    ]
     .into_iter()
-    .collect();
+    .collect()
+}
 
-    let matches = App::new("VRP Solver")
-        .version("0.1")
-        .author("Ilya Builuk <ilya.builuk@gmail.com>")
-        .about("Solves variations of Vehicle Routing Problem")
-        .arg(Arg::with_name("PROBLEM").help("Sets the problem file to use").required(true).index(1))
-        .arg(
-            Arg::with_name("FORMAT")
-                .help("Specifies the problem type")
-                .required(true)
-                .possible_values(readers.keys().map(|s| s.deref()).collect::<Vec<&str>>().as_slice())
-                .index(2),
-        )
-        .get_matches();
+fn create_in_format_arg() -> Arg<'static, 'static> {
+    Arg::with_name("in-format")
+        .long("in-format")
+        .help("Specifies input problem format")
+        .required(true)
+        .takes_value(true)
+        .possible_values(get_readers().keys().map(|s| s.deref()).collect::<Vec<&str>>().as_slice())
+}
 
-    let problem_path = matches.value_of("PROBLEM").unwrap();
-    let problem_format = matches.value_of("FORMAT").unwrap();
-    let input_reader = readers.get(problem_format).unwrap();
+fn create_out_format_arg() -> Arg<'static, 'static> {
+    Arg::with_name("out-format")
+        .long("out-format")
+        .help("Specifies output solution format")
+        .required(true)
+        .takes_value(true)
+        .possible_values(get_writers(false).keys().map(|s| s.deref()).collect::<Vec<&str>>().as_slice())
+}
+
+fn create_problem_arg() -> Arg<'static, 'static> {
+    Arg::with_name("PROBLEM").help("Sets the problem file to use").required(true).index(1)
+}
+
+fn create_out_arg() -> Arg<'static, 'static> {
+    Arg::with_name("out").long("out").help("Writes solution to file instead of stdout").takes_value(true)
+}
+
+fn create_dark_mode_arg() -> Arg<'static, 'static> {
+    Arg::with_name("dark-mode")
+        .long("dark-mode")
+        .help("Uses a light-on-dark palette, for the 'dot' output format")
+        .takes_value(false)
+}
 
-    let solution = match input_reader.0(problem_path.to_string()) {
-        Ok(problem) => Solver::default().solve(problem),
+/// Reads problem, exiting the process with a clean error message on failure. Used by the
+/// one-shot subcommands and the initial read in `watch`, where a bad path/format is a usage
+/// error rather than something to recover from.
+fn read_problem(matches: &ArgMatches) -> Problem {
+    match try_read_problem(matches) {
+        Ok(problem) => problem,
         Err(error) => {
+            let problem_path = matches.value_of("PROBLEM").unwrap();
+            let problem_format = matches.value_of("in-format").unwrap();
             eprintln!("Cannot read {} problem from '{}': '{}'", problem_format, problem_path, error);
             process::exit(1);
         }
-    };
+    }
+}
+
+/// Reads problem without exiting the process, so that callers which should survive a
+/// transient parse failure (e.g. `watch` re-reading a file mid-edit) can decide what to do.
+fn try_read_problem(matches: &ArgMatches) -> Result<Problem, String> {
+    let readers = get_readers();
+
+    let problem_path = matches.value_of("PROBLEM").unwrap();
+    let problem_format = matches.value_of("in-format").unwrap();
+    let input_reader = readers.get(problem_format).unwrap();
+
+    input_reader.0(problem_path.to_string())
+}
+
+fn create_writer(out_path: Option<&str>) -> Result<Box<dyn Write>, Error> {
+    match out_path {
+        Some(path) => File::create(path).map(|file| Box::new(BufWriter::new(file)) as Box<dyn Write>),
+        None => Ok(Box::new(BufWriter::new(stdout()))),
+    }
+}
+
+/// Writes solution, exiting the process with a clean error message on failure. Used by the
+/// one-shot subcommands and the initial write in `watch`, where a bad `--out` path is a usage
+/// error rather than something to recover from.
+fn write_solution(matches: &ArgMatches, solution: Solution) {
+    if let Err(error) = try_write_solution(matches, solution) {
+        eprintln!("Cannot write solution: '{}'", error);
+        process::exit(1);
+    }
+}
+
+/// Writes solution without exiting the process, so that callers which should survive a
+/// transient write failure (e.g. `watch` writing to an `--out` path that's momentarily
+/// unwritable) can decide what to do.
+fn try_write_solution(matches: &ArgMatches, solution: Solution) -> Result<(), Error> {
+    let writers = get_writers(matches.is_present("dark-mode"));
+
+    let solution_format = matches.value_of("out-format").unwrap();
+    let writer = writers.get(solution_format).unwrap();
+    let out = create_writer(matches.value_of("out"))?;
+
+    writer.0(solution, out)
+}
+
+fn run_solve(matches: &ArgMatches) {
+    let problem = read_problem(matches);
+    let solution = Solver::default().solve(problem);
+
+    write_solution(matches, solution);
+}
+
+fn run_validate(matches: &ArgMatches) {
+    let _problem = read_problem(matches);
+    println!("Problem is valid.");
+}
+
+fn run_analyze(matches: &ArgMatches) {
+    let problem_path = matches.value_of("PROBLEM").unwrap();
+    let problem = read_problem(matches);
+    let solution = Solver::default().solve(problem);
+
+    println!("Analyzed '{}', writing resulting solution:", problem_path);
+    write_solution(matches, solution);
+}
+
+fn read_modified_time(path: &str) -> Result<SystemTime, Error> {
+    Path::new(path).metadata().and_then(|metadata| metadata.modified())
+}
+
+fn run_watch(matches: &ArgMatches) {
+    let problem_path = matches.value_of("PROBLEM").unwrap().to_string();
+    let poll_interval = Duration::from_millis(500);
+
+    let mut last_modified = read_modified_time(&problem_path).unwrap_or_else(|error| {
+        eprintln!("Cannot read '{}' metadata: '{}'", problem_path, error);
+        process::exit(1);
+    });
+
+    println!("Watching '{}' for changes. Press Ctrl+C to stop.", problem_path);
+
+    // The initial read happens before the loop: a failure here means the CLI invocation
+    // itself is wrong (bad path/format), so it's still a hard error.
+    let problem = read_problem(matches);
+    write_solution(matches, Solver::default().solve(problem));
+
+    loop {
+        sleep(poll_interval);
+
+        let modified = match read_modified_time(&problem_path) {
+            Ok(modified) => modified,
+            Err(error) if error.kind() == ErrorKind::NotFound => continue,
+            Err(error) => {
+                eprintln!("Cannot read '{}' metadata: '{}'", problem_path, error);
+                continue;
+            }
+        };
+
+        if modified > last_modified {
+            last_modified = modified;
+            println!("'{}' changed, re-solving...", problem_path);
+
+            // A transient/partial save while the user is still editing, or a `--out` path
+            // that's momentarily unwritable, shouldn't kill the watcher: log it and wait for
+            // the next change instead of exiting the process.
+            match try_read_problem(matches) {
+                Ok(problem) => {
+                    let solution = Solver::default().solve(problem);
+                    if let Err(error) = try_write_solution(matches, solution) {
+                        eprintln!("Cannot write solution, waiting for next change: '{}'", error);
+                    }
+                }
+                Err(error) => eprintln!("Cannot read problem, waiting for next change: '{}'", error),
+            }
+        }
+    }
+}
+
+fn main() {
+    let matches = App::new("VRP Solver")
+        .version("0.1")
+        .author("Ilya Builuk <ilya.builuk@gmail.com>")
+        .about("Solves variations of Vehicle Routing Problem")
+        .subcommand(
+            SubCommand::with_name("solve")
+                .about("Solves the problem and writes the solution")
+                .arg(create_problem_arg())
+                .arg(create_in_format_arg())
+                .arg(create_out_format_arg())
+                .arg(create_out_arg())
+                .arg(create_dark_mode_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("validate")
+                .about("Validates that the problem can be parsed")
+                .arg(create_problem_arg())
+                .arg(create_in_format_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("analyze")
+                .about("Solves the problem and writes the solution after a header line naming the problem")
+                .arg(create_problem_arg())
+                .arg(create_in_format_arg())
+                .arg(create_out_format_arg())
+                .arg(create_out_arg())
+                .arg(create_dark_mode_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("watch")
+                .about("Re-solves the problem each time its file changes on disk")
+                .arg(create_problem_arg())
+                .arg(create_in_format_arg())
+                .arg(create_out_format_arg())
+                .arg(create_out_arg())
+                .arg(create_dark_mode_arg()),
+        )
+        .get_matches();
 
-    match writers.get(problem_format) {
-        Some(writer) => writer.0(solution).unwrap(),
+    match matches.subcommand() {
+        ("solve", Some(matches)) => run_solve(matches),
+        ("validate", Some(matches)) => run_validate(matches),
+        ("analyze", Some(matches)) => run_analyze(matches),
+        ("watch", Some(matches)) => run_watch(matches),
         _ => {
-            // TODO
-            eprintln!("Don't know how to write solution in '{}' format", problem_format);
+            eprintln!("No subcommand given, see --help for usage.");
             process::exit(1);
         }
     }
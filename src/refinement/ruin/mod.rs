@@ -5,6 +5,11 @@ pub trait Ruin {
     fn run(&self, mut insertion_ctx: InsertionContext) -> InsertionContext;
 }
 
+// TODO: AdjustedStringRemoval and RandomRouteRemoval still pre-compute indices and keep
+// `activities`/`jobs` in sync by hand. Now that `Tour::drain_jobs` exists, their removals
+// should go through it instead. Left as a follow-up: neither operator's source nor the
+// `construction::states` types they're built on are part of this checkout, so the refactor
+// can't be done in this commit without guessing at APIs this crate doesn't expose here.
 mod adjusted_string_removal;
 pub use self::adjusted_string_removal::AdjustedStringRemoval;
 
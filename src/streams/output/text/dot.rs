@@ -0,0 +1,76 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/streams/output/text/dot_test.rs"]
+mod dot_test;
+
+use crate::models::solution::{Route, TourActivity};
+use crate::models::Solution;
+use std::io::{Error, Write};
+
+/// Distinct colors assigned to vehicle routes, cycled when there are more routes than colors.
+const ROUTE_COLORS: &[&str] =
+    &["#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f"];
+
+/// Writes solution as a Graphviz DOT graph: one node per activity, one edge per tour leg between
+/// two activities (a leg holding a single activity gets no edge) and one cluster per vehicle
+/// route so that tours can be told apart visually.
+pub fn write_dot_solution<W: Write>(mut writer: W, solution: &Solution, dark_mode: bool) -> Result<(), Error> {
+    let (background_color, text_color) = if dark_mode { ("#1e1e1e", "#f0f0f0") } else { ("#ffffff", "#000000") };
+
+    writeln!(writer, "digraph Solution {{")?;
+    writeln!(writer, "  bgcolor=\"{}\";", background_color)?;
+    writeln!(writer, "  node [style=filled, fontcolor=\"{}\"];", text_color)?;
+    writeln!(writer, "  edge [fontcolor=\"{}\"];", text_color)?;
+
+    for (route_idx, route) in solution.routes.iter().enumerate() {
+        write_route(&mut writer, route, route_idx, text_color)?;
+    }
+
+    writeln!(writer, "}}")?;
+
+    Ok(())
+}
+
+fn write_route<W: Write>(writer: &mut W, route: &Route, route_idx: usize, text_color: &str) -> Result<(), Error> {
+    let fill_color = ROUTE_COLORS[route_idx % ROUTE_COLORS.len()];
+
+    writeln!(writer, "  subgraph cluster_{} {{", route_idx)?;
+    writeln!(writer, "    label=\"route {}\"; color=\"{}\"; fontcolor=\"{}\";", route_idx, fill_color, text_color)?;
+
+    for (activity_idx, activity) in route.tour.all_activities().enumerate() {
+        writeln!(
+            writer,
+            "    \"{}\" [label=\"{}\", fillcolor=\"{}\", color=\"{}\"];",
+            node_id(route_idx, activity_idx),
+            node_label(activity),
+            fill_color,
+            text_color
+        )?;
+    }
+
+    for (leg, leg_idx) in route.tour.legs() {
+        if leg.len() == 2 {
+            writeln!(
+                writer,
+                "    \"{}\" -> \"{}\" [color=\"{}\"];",
+                node_id(route_idx, leg_idx),
+                node_id(route_idx, leg_idx + 1),
+                text_color
+            )?;
+        }
+    }
+
+    writeln!(writer, "  }}")?;
+
+    Ok(())
+}
+
+fn node_id(route_idx: usize, activity_idx: usize) -> String {
+    format!("r{}a{}", route_idx, activity_idx)
+}
+
+fn node_label(activity: &TourActivity) -> String {
+    match &activity.job {
+        Some(_) => format!("job\\nloc {}", activity.place.location),
+        None => format!("depot\\nloc {}", activity.place.location),
+    }
+}
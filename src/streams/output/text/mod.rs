@@ -0,0 +1,5 @@
+mod solomon;
+pub use self::solomon::write_solomon_solution;
+
+mod dot;
+pub use self::dot::write_dot_solution;
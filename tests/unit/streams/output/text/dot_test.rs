@@ -0,0 +1,42 @@
+use super::*;
+use crate::helpers::models::problem::test_single_job_with_id;
+use crate::helpers::models::solution::{test_activity_with_job, test_activity_without_job, test_route_with_tour, test_solution_with_routes};
+use crate::models::solution::Tour;
+
+fn create_solution_with_one_route() -> Solution {
+    let job = test_single_job_with_id("job1");
+
+    let mut tour = Tour::default();
+    tour.set_start(Box::new(test_activity_without_job()));
+    tour.insert_last(Box::new(test_activity_with_job(job)));
+    tour.set_end(Box::new(test_activity_without_job()));
+
+    test_solution_with_routes(vec![test_route_with_tour(tour)])
+}
+
+#[test]
+fn can_write_dot_solution_in_light_mode() {
+    let solution = create_solution_with_one_route();
+    let mut buffer = Vec::new();
+
+    write_dot_solution(&mut buffer, &solution, false).unwrap();
+    let dot = String::from_utf8(buffer).unwrap();
+
+    assert!(dot.starts_with("digraph Solution {"));
+    assert!(dot.contains("bgcolor=\"#ffffff\""));
+    assert!(dot.contains("subgraph cluster_0"));
+    assert!(dot.contains("\"r0a0\" -> \"r0a1\" [color=\"#000000\"]"));
+    assert!(dot.contains("color=\"#000000\""));
+}
+
+#[test]
+fn can_write_dot_solution_in_dark_mode() {
+    let solution = create_solution_with_one_route();
+    let mut buffer = Vec::new();
+
+    write_dot_solution(&mut buffer, &solution, true).unwrap();
+    let dot = String::from_utf8(buffer).unwrap();
+
+    assert!(dot.contains("bgcolor=\"#1e1e1e\""));
+    assert!(dot.contains("\"r0a0\" -> \"r0a1\" [color=\"#f0f0f0\"]"));
+}
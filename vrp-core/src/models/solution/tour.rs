@@ -102,6 +102,29 @@ impl Tour {
         jobs
     }
 
+    /// Removes all activities whose job matches given predicate, keeping start/end activities
+    /// in place, and returns the jobs removed. Each matched job is returned once even if it
+    /// spans multiple activities.
+    pub fn drain_jobs<F>(&mut self, mut predicate: F) -> Vec<Job>
+    where
+        F: FnMut(&Job) -> bool,
+    {
+        let mut removed = Vec::new();
+        let jobs = &mut self.jobs;
+
+        self.activities.retain(|a| match a.retrieve_job() {
+            Some(job) if predicate(&job) => {
+                if jobs.remove(&job) {
+                    removed.push(job);
+                }
+                false
+            }
+            _ => true,
+        });
+
+        removed
+    }
+
     /// Returns all activities in tour.
     pub fn all_activities(&self) -> Iter<TourActivity> {
         self.activities.iter()
@@ -0,0 +1,68 @@
+use super::*;
+use crate::helpers::models::problem::test_single_job_with_id;
+use crate::helpers::models::solution::{test_activity_with_job, test_activity_without_job};
+
+fn create_tour_with_jobs(ids: &[&str]) -> (Tour, Vec<Job>) {
+    let jobs: Vec<_> = ids.iter().map(|id| test_single_job_with_id(id)).collect();
+
+    let mut tour = Tour::default();
+    tour.set_start(Box::new(test_activity_without_job()));
+    jobs.iter().cloned().for_each(|job| {
+        tour.insert_last(Box::new(test_activity_with_job(job)));
+    });
+    tour.set_end(Box::new(test_activity_without_job()));
+
+    (tour, jobs)
+}
+
+#[test]
+fn can_drain_jobs_matching_predicate() {
+    let (mut tour, jobs) = create_tour_with_jobs(&["job1", "job2", "job3"]);
+
+    let removed = tour.drain_jobs(|job| job != &jobs[1]);
+
+    assert_eq!(removed.len(), 2);
+    assert!(removed.contains(&jobs[0]));
+    assert!(removed.contains(&jobs[2]));
+    assert_eq!(tour.job_count(), 1);
+    assert!(tour.contains(&jobs[1]));
+}
+
+#[test]
+fn can_drain_job_spanning_multiple_activities_only_once() {
+    let job = test_single_job_with_id("job1");
+    let mut tour = Tour::default();
+    tour.set_start(Box::new(test_activity_without_job()));
+    tour.insert_last(Box::new(test_activity_with_job(job.clone())));
+    tour.insert_last(Box::new(test_activity_with_job(job.clone())));
+    tour.set_end(Box::new(test_activity_without_job()));
+
+    let removed = tour.drain_jobs(|j| j == &job);
+
+    assert_eq!(removed, vec![job]);
+    assert_eq!(tour.total(), 2);
+}
+
+#[test]
+fn drain_jobs_never_removes_start_or_end_activity() {
+    let (mut tour, _) = create_tour_with_jobs(&["job1"]);
+
+    let removed = tour.drain_jobs(|_| true);
+
+    assert_eq!(removed.len(), 1);
+    assert_eq!(tour.total(), 2);
+    assert!(tour.start().is_some());
+    assert!(tour.end().is_some());
+}
+
+#[test]
+fn drain_jobs_with_non_matching_predicate_leaves_tour_untouched() {
+    let (mut tour, jobs) = create_tour_with_jobs(&["job1", "job2"]);
+    let total_before = tour.total();
+
+    let removed = tour.drain_jobs(|_| false);
+
+    assert!(removed.is_empty());
+    assert_eq!(tour.total(), total_before);
+    assert_eq!(tour.job_count(), jobs.len());
+}